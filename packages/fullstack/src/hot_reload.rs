@@ -8,13 +8,31 @@ use tokio::sync::{
     RwLock,
 };
 
+/// An update streamed to connected dev browsers so they can patch the running
+/// app without a full reload.
+///
+/// Serializing [`Template`] requires dioxus-core's `serialize` feature, which
+/// the fullstack server enables alongside hot reloading.
+#[derive(Clone, Debug, serde::Serialize)]
+pub enum DevserverMsg {
+    /// A changed template that should be diffed into the live VDOM.
+    UpdateTemplate(Template<'static>),
+    /// A change that can't be sent as a template diff (such as a changed static
+    /// asset, or any rebuild reported as a shutdown); the browser should reload
+    /// to pick it up.
+    Reload,
+}
+
 /// The hot-reload state.
 #[derive(Clone)]
 pub struct HotReloadState {
     /// The cache of all templates that have been modified since the last time we checked
     pub templates: Arc<RwLock<std::collections::HashSet<dioxus::prelude::Template<'static>>>>,
-    /// The channel to send messages to the hot reload thread
-    pub message_receiver: Receiver<Option<Template<'static>>>,
+    /// The channel hot-reload updates are published on. Connected browsers
+    /// `clone` the receiver and await `changed()`; carrying [`DevserverMsg`]
+    /// lets template diffs and asset reloads share the one channel the request
+    /// side already subscribes to.
+    pub message_receiver: Receiver<Option<DevserverMsg>>,
 }
 
 impl Default for HotReloadState {
@@ -31,12 +49,18 @@ impl Default for HotReloadState {
                         templates.insert(template);
                     }
 
-                    if let Err(err) = tx.send(Some(template)) {
+                    if let Err(err) = tx.send(Some(DevserverMsg::UpdateTemplate(template))) {
                         tracing::error!("Failed to send hot reload message: {}", err);
                     }
                 }
+                // A changed asset (or any rebuild the toolchain reports as a
+                // shutdown) can't be expressed as a template diff, so push a
+                // targeted reload to connected browsers instead of tearing the
+                // server down.
                 dioxus_hot_reload::HotReloadMsg::Shutdown => {
-                    std::process::exit(0);
+                    if let Err(err) = tx.send(Some(DevserverMsg::Reload)) {
+                        tracing::error!("Failed to send hot reload message: {}", err);
+                    }
                 }
             }
         });
@@ -64,3 +88,73 @@ pub async fn spawn_hot_reload() -> &'static HotReloadState {
         })
         .await
 }
+
+/// Build a router exposing the hot-reload WebSocket endpoint.
+///
+/// Merge this into the fullstack server's router so dev browsers receive
+/// template diffs and asset reloads over the existing serve layer.
+#[cfg(feature = "axum")]
+pub async fn hot_reload_router() -> axum::Router {
+    let _ = spawn_hot_reload().await;
+    axum::Router::new().route("/_dioxus/hot_reload", axum::routing::get(hot_reload_handler))
+}
+
+/// Axum handler that streams hot-reload updates to a connected dev browser over
+/// a WebSocket.
+///
+/// It subscribes to the shared [`HotReloadState`], replaying the templates
+/// modified so far so a freshly connected browser catches up, then forwards
+/// each [`DevserverMsg`] as it arrives so the client can patch the live VDOM
+/// (or reload a changed asset) without a full page refresh.
+#[cfg(feature = "axum")]
+pub async fn hot_reload_handler(ws: axum::extract::ws::WebSocketUpgrade) -> axum::response::Response {
+    let state = spawn_hot_reload().await;
+    ws.on_upgrade(|socket| hot_reload_socket(socket, state))
+}
+
+#[cfg(feature = "axum")]
+async fn hot_reload_socket(mut socket: axum::extract::ws::WebSocket, state: &HotReloadState) {
+    // Clone the receiver before replaying so no update published during the
+    // replay is missed, and mark the message already published as seen so it
+    // isn't forwarded again to a browser that only just connected.
+    let mut receiver = state.message_receiver.clone();
+    receiver.borrow_and_update();
+
+    {
+        let templates = state.templates.read().await;
+        for template in templates.iter() {
+            if send_msg(&mut socket, &DevserverMsg::UpdateTemplate(*template))
+                .await
+                .is_err()
+            {
+                return;
+            }
+        }
+    }
+
+    // `changed()` only errors once the sender, which lives in the hot-reload
+    // connection callback rather than in the shared state, is dropped.
+    while receiver.changed().await.is_ok() {
+        let msg = receiver.borrow_and_update().clone();
+        if let Some(msg) = msg {
+            if send_msg(&mut socket, &msg).await.is_err() {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "axum")]
+async fn send_msg(
+    socket: &mut axum::extract::ws::WebSocket,
+    msg: &DevserverMsg,
+) -> Result<(), axum::Error> {
+    let text = match serde_json::to_string(msg) {
+        Ok(text) => text,
+        Err(err) => {
+            tracing::error!("Failed to serialize hot reload message: {}", err);
+            return Ok(());
+        }
+    };
+    socket.send(axum::extract::ws::Message::Text(text)).await
+}
@@ -6,9 +6,174 @@ use crate::router::*;
 use std::fs::File;
 use std::io::Read;
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 
 use dioxus::prelude::*;
 
+/// A pluggable backing store for the incremental render cache.
+///
+/// By default the incremental renderer persists prerendered pages to the local
+/// filesystem, which is fine for a single long-lived node but breaks down in
+/// serverless or multi-node deployments where the disk is ephemeral or not
+/// shared. Implement this trait to back the cache with Redis, S3, an in-process
+/// LRU, or any other store, and hand it to
+/// [`ServeConfigBuilder::incremental_with_store`]. This mirrors the pluggable
+/// config-manager abstraction used for static site generation.
+#[async_trait::async_trait]
+pub trait CacheStore: Send + Sync + 'static {
+    /// Fetch the cached bytes for a route along with the time they were generated.
+    async fn get(&self, route: &str) -> Option<(Vec<u8>, SystemTime)>;
+
+    /// Store the rendered bytes for a route.
+    async fn put(&self, route: &str, bytes: Vec<u8>);
+
+    /// Drop the cached entry for a route so the next request re-renders it.
+    async fn invalidate(&self, route: &str);
+}
+
+/// The directory the default filesystem render cache is rooted at when
+/// incremental rendering is enabled without an explicit store. Matches the
+/// incremental renderer's default output directory and is deliberately separate
+/// from `assets_path` so cached renders never collide with the index template.
+const DEFAULT_INCREMENTAL_CACHE_DIR: &str = "./static";
+
+/// The default [`CacheStore`] that persists prerendered pages under a directory
+/// on the local filesystem, preserving the pre-store behavior of the
+/// incremental renderer.
+#[derive(Clone)]
+pub struct FileCacheStore {
+    root: PathBuf,
+}
+
+impl FileCacheStore {
+    /// Create a new filesystem store rooted at `root`.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Map the route onto a file under the cache root, treating "/" as the
+    /// index so every route resolves to a concrete file name.
+    ///
+    /// Returns `None` for any route that can't be expressed as plain segments
+    /// under the root — empty segments from doubled slashes, `.`/`..`, or
+    /// absolute/prefix components — so a crafted request path (e.g.
+    /// `/../../etc/passwd`) can't read or write outside the cache directory.
+    fn path_for(&self, route: &str) -> Option<PathBuf> {
+        let trimmed = route.trim_matches('/');
+        if trimmed.is_empty() {
+            return Some(self.root.join("index.html"));
+        }
+
+        let mut path = self.root.clone();
+        for segment in trimmed.split('/') {
+            // Accept only segments that are a single `Normal` path component.
+            let mut components = std::path::Path::new(segment).components();
+            match (components.next(), components.next()) {
+                (Some(std::path::Component::Normal(name)), None) => path.push(name),
+                _ => return None,
+            }
+        }
+        Some(path.join("index.html"))
+    }
+}
+
+#[async_trait::async_trait]
+impl CacheStore for FileCacheStore {
+    async fn get(&self, route: &str) -> Option<(Vec<u8>, SystemTime)> {
+        let path = self.path_for(route)?;
+        let metadata = tokio::fs::metadata(&path).await.ok()?;
+        let generated_at = metadata.modified().ok()?;
+        let bytes = tokio::fs::read(&path).await.ok()?;
+        Some((bytes, generated_at))
+    }
+
+    async fn put(&self, route: &str, bytes: Vec<u8>) {
+        let path = match self.path_for(route) {
+            Some(path) => path,
+            None => {
+                tracing::error!("Refusing to cache route outside the cache root: {route}");
+                return;
+            }
+        };
+        if let Some(parent) = path.parent() {
+            if let Err(err) = tokio::fs::create_dir_all(parent).await {
+                tracing::error!("Failed to create cache directory: {}", err);
+                return;
+            }
+        }
+        if let Err(err) = tokio::fs::write(&path, bytes).await {
+            tracing::error!("Failed to write cache entry: {}", err);
+        }
+    }
+
+    async fn invalidate(&self, route: &str) {
+        let path = match self.path_for(route) {
+            Some(path) => path,
+            None => return,
+        };
+        if let Err(err) = tokio::fs::remove_file(&path).await {
+            if err.kind() != std::io::ErrorKind::NotFound {
+                tracing::error!("Failed to invalidate cache entry: {}", err);
+            }
+        }
+    }
+}
+
+/// How a route is produced, declared per-route instead of the all-or-nothing
+/// `incremental` flag on [`ServeConfig`].
+///
+/// This imports the render-config idea from build-time SSG frameworks where each
+/// page template chooses its own generation mode. The server consults the
+/// strategy when a request comes in (see
+/// [`ServeConfigBuilder::route_strategies`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RenderStrategy {
+    /// Prerendered at build time and served from the store unconditionally; a
+    /// request for a route that was never prerendered is a 404
+    /// ([`RenderError::MissingStatic`]) rather than a server render.
+    Static,
+    /// Served from the store, regenerating in the background when the cached
+    /// entry is older than `revalidate`.
+    Incremental {
+        /// How long a cached entry stays fresh before a background re-render is
+        /// triggered. `None` means the entry never expires on its own.
+        revalidate: Option<Duration>,
+    },
+    /// Rendered per-request with [`dioxus-ssr`], never cached.
+    Server,
+}
+
+impl Default for RenderStrategy {
+    fn default() -> Self {
+        Self::Server
+    }
+}
+
+/// An error produced while rendering a route for an incoming request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RenderError {
+    /// A [`RenderStrategy::Static`] route was requested but no prerendered
+    /// entry exists in the store. Static pages are generated ahead of time, so
+    /// the server surfaces this as a 404 instead of rendering on the fly.
+    MissingStatic(String),
+}
+
+impl std::fmt::Display for RenderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RenderError::MissingStatic(route) => {
+                write!(f, "no prerendered static page for route `{route}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RenderError {}
+
+/// Resolves the [`RenderStrategy`] for a given route at request time.
+pub(crate) type RouteStrategies = Arc<dyn Fn(&str) -> RenderStrategy + Send + Sync>;
+
 /// A ServeConfig is used to configure how to serve a Dioxus application. It contains information about how to serve static assets, and what content to render with [`dioxus-ssr`].
 #[derive(Clone)]
 pub struct ServeConfigBuilder<P: Clone> {
@@ -17,10 +182,57 @@ pub struct ServeConfigBuilder<P: Clone> {
     pub(crate) root_id: Option<&'static str>,
     pub(crate) index_path: Option<&'static str>,
     pub(crate) assets_path: Option<&'static str>,
-    pub(crate) incremental:
-        Option<std::sync::Arc<dioxus_ssr::incremental::IncrementalRendererConfig>>,
+    pub(crate) incremental: Option<IncrementalConfig>,
+    pub(crate) store: Option<Arc<dyn CacheStore>>,
+    pub(crate) strategies: Option<RouteStrategies>,
+    pub(crate) props_for_route: Option<PropsForRoute<P>>,
+}
+
+/// Settings for the incremental (stale-while-revalidate) render path.
+///
+/// Only the settings the fullstack serve layer actually honors are exposed: the
+/// revalidation TTL and the directory the default filesystem [`CacheStore`] is
+/// rooted at. Persistence and body wrapping are the responsibility of the
+/// [`CacheStore`] implementation, so — unlike `dioxus_ssr`'s
+/// `IncrementalRendererConfig` — there is nothing here that is accepted and then
+/// silently ignored.
+#[derive(Clone, Default)]
+pub struct IncrementalConfig {
+    pub(crate) invalidate_after: Option<Duration>,
+    pub(crate) cache_dir: Option<PathBuf>,
 }
 
+impl IncrementalConfig {
+    /// A config that never expires entries on its own and uses the default
+    /// cache directory.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serve a cached entry stale and re-render it in the background once it is
+    /// older than `duration`.
+    pub fn invalidate_after(mut self, duration: Duration) -> Self {
+        self.invalidate_after = Some(duration);
+        self
+    }
+
+    /// Root the default filesystem [`CacheStore`] at `dir` instead of the
+    /// default cache directory. Ignored when a custom store is supplied through
+    /// [`ServeConfigBuilder::incremental_with_store`].
+    pub fn cache_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = Some(dir.into());
+        self
+    }
+}
+
+/// Rebuilds the render props for a specific route.
+///
+/// Router builders install this so the requested path is threaded into
+/// [`FullstackRouterConfig`] before SSR — otherwise every route would render
+/// the default route's HTML. Non-router apps leave it unset and render the same
+/// props for every route.
+pub(crate) type PropsForRoute<P> = Arc<dyn Fn(&str) -> P + Send + Sync>;
+
 /// A template for incremental rendering that does nothing.
 #[derive(Default, Clone)]
 pub struct EmptyIncrementalRenderTemplate;
@@ -41,6 +253,25 @@ impl dioxus_ssr::incremental::WrapBody for EmptyIncrementalRenderTemplate {
     }
 }
 
+/// Declares which concrete paths a `Routable` router should expand to when
+/// exporting a static site.
+///
+/// Routes made entirely of static segments are enumerated automatically from
+/// the router's site map; dynamic segments (such as `/blog/:slug`) cannot be
+/// known ahead of time, so a router opts in to build-time rendering of those
+/// pages by implementing this trait and returning the concrete paths to render.
+/// This mirrors the `getStaticPaths` hook found in build-time SSG frameworks.
+#[cfg(feature = "router")]
+pub trait GetStaticPaths: dioxus_router::prelude::Routable {
+    /// Return the concrete paths that should be rendered for dynamic segments.
+    ///
+    /// Defaults to an empty set, in which case only the fully-static routes are
+    /// exported.
+    fn get_static_paths() -> Vec<String> {
+        Vec::new()
+    }
+}
+
 #[cfg(feature = "router")]
 impl<R> ServeConfigBuilder<FullstackRouterConfig<R>>
 where
@@ -49,7 +280,150 @@ where
 {
     /// Create a new ServeConfigBuilder to serve a router on the server.
     pub fn new_with_router(cfg: FullstackRouterConfig<R>) -> Self {
-        Self::new(RouteWithCfg::<R>, cfg)
+        let mut builder = Self::new(RouteWithCfg::<R>, cfg);
+        // Thread the requested route into the router config before SSR, the same
+        // way `render_route_into` does on the build-time path, so each route
+        // renders its own page instead of the default route's HTML.
+        builder.props_for_route = Some(Arc::new(|route: &str| {
+            FullstackRouterConfig::<R>::default().initial_route(route.to_string())
+        }));
+        builder
+    }
+
+    /// Declare how each route is produced.
+    ///
+    /// The closure is keyed by the parsed [`Routable`](dioxus_router::prelude::Routable)
+    /// route and returns the [`RenderStrategy`] for it. At request time the
+    /// server consults this: [`RenderStrategy::Static`] serves from the store
+    /// unconditionally, [`RenderStrategy::Incremental`] serves from the store and
+    /// regenerates in the background once the entry is older than `revalidate`,
+    /// and [`RenderStrategy::Server`] always calls [`dioxus-ssr`]. Routes that
+    /// fail to parse fall back to [`RenderStrategy::Server`].
+    pub fn route_strategies(
+        mut self,
+        strategies: impl Fn(&R) -> RenderStrategy + Send + Sync + 'static,
+    ) -> Self {
+        self.strategies = Some(Arc::new(move |route: &str| match route.parse::<R>() {
+            Ok(route) => strategies(&route),
+            Err(_) => RenderStrategy::Server,
+        }));
+        self
+    }
+
+    /// Export the router as a static site under `out_dir`.
+    ///
+    /// Every concrete path reachable from the `Routable` router is enumerated
+    /// (fully-static routes from the site map, plus any paths declared by
+    /// [`GetStaticPaths::get_static_paths`] for dynamic segments), rendered with
+    /// [`dioxus-ssr`], and written into a directory tree mirroring the routes so
+    /// that `/blog/post` becomes `blog/post/index.html`. The prerendered content
+    /// is injected into the index template using the same pre/post-main split
+    /// used when serving, producing output any static host can serve directly.
+    pub async fn generate_static_site(
+        self,
+        out_dir: impl Into<PathBuf>,
+    ) -> Result<(), dioxus_ssr::incremental::IncrementalRendererError>
+    where
+        R: GetStaticPaths,
+    {
+        let out_dir = out_dir.into();
+        let config = self.build();
+
+        for route in static_routes::<R>() {
+            let mut html = String::new();
+            html.push_str(&config.index.pre_main);
+            render_route_into::<R>(&route, &mut html)?;
+            html.push_str(&config.index.post_main);
+
+            let path = static_site_path(&out_dir, &route);
+            if let Some(parent) = path.parent() {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+            tokio::fs::write(&path, html).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Enumerate every concrete path reachable from a router: the fully-static
+/// routes discovered from the site map, followed by the dynamic paths the app
+/// declares through [`GetStaticPaths`].
+#[cfg(feature = "router")]
+fn static_routes<R: GetStaticPaths>() -> Vec<String> {
+    use dioxus_router::prelude::Routable;
+
+    let mut routes = Vec::new();
+    flatten_site_map("", R::SITE_MAP, &mut routes);
+    // Always export the index page, regardless of what other static routes the
+    // site map yields; it is only skipped here if the map already emitted "/".
+    if !routes.iter().any(|route| route == "/") {
+        routes.insert(0, "/".to_string());
+    }
+    // Dynamic segments can't be enumerated from the site map, so their concrete
+    // paths come from the app via get_static_paths().
+    routes.extend(R::get_static_paths());
+    routes
+}
+
+/// Collect the fully-static paths from a router site map.
+///
+/// Every static segment is emitted as a renderable endpoint — including
+/// intermediate ones that also have children (e.g. a `/blog` index sitting
+/// above a `:id` child) — and its static children are then expanded under the
+/// correct prefix. Dynamic and catch-all segments are left untouched: they
+/// can't be expanded from the site map without inventing a path (which would
+/// produce a route the router can't resolve, like turning `/blog/:slug/comments`
+/// into `/blog/comments`), so the app supplies them through
+/// [`GetStaticPaths::get_static_paths`] instead.
+#[cfg(feature = "router")]
+fn flatten_site_map(
+    prefix: &str,
+    segments: &[dioxus_router::prelude::SiteMapSegment],
+    out: &mut Vec<String>,
+) {
+    for segment in segments {
+        match &segment.segment_type {
+            dioxus_router::prelude::SegmentType::Static(name) => {
+                let path = format!("{prefix}/{name}");
+                out.push(path.clone());
+                flatten_site_map(&path, segment.children, out);
+            }
+            // Dynamic/catch-all segments (and their descendants) depend on
+            // parameters we don't have here; skip them rather than emit a path
+            // with the segment dropped.
+            _ => {}
+        }
+    }
+}
+
+/// Render a single route with `dioxus-ssr` into `out`.
+#[cfg(feature = "router")]
+fn render_route_into<R>(
+    route: &str,
+    out: &mut String,
+) -> Result<(), dioxus_ssr::incremental::IncrementalRendererError>
+where
+    R: dioxus_router::prelude::Routable,
+    <R as std::str::FromStr>::Err: std::fmt::Display,
+{
+    let cfg = FullstackRouterConfig::<R>::default().initial_route(route.to_string());
+    let mut vdom = VirtualDom::new_with_props(RouteWithCfg::<R>, cfg);
+    let _ = vdom.rebuild();
+    out.push_str(&dioxus_ssr::render(&vdom));
+    Ok(())
+}
+
+/// Map a route onto the `index.html` file that represents it under `out_dir`,
+/// so `/blog/post` becomes `out_dir/blog/post/index.html` and `/` becomes
+/// `out_dir/index.html`.
+#[cfg(feature = "router")]
+fn static_site_path(out_dir: &std::path::Path, route: &str) -> PathBuf {
+    let trimmed = route.trim_matches('/');
+    if trimmed.is_empty() {
+        out_dir.join("index.html")
+    } else {
+        out_dir.join(trimmed).join("index.html")
     }
 }
 
@@ -63,12 +437,32 @@ impl<P: Clone> ServeConfigBuilder<P> {
             index_path: None,
             assets_path: None,
             incremental: None,
+            store: None,
+            strategies: None,
+            props_for_route: None,
         }
     }
 
-    /// Enable incremental static generation
-    pub fn incremental(mut self, cfg: dioxus_ssr::incremental::IncrementalRendererConfig) -> Self {
-        self.incremental = Some(std::sync::Arc::new(cfg));
+    /// Enable incremental static generation.
+    pub fn incremental(mut self, cfg: IncrementalConfig) -> Self {
+        self.incremental = Some(cfg);
+        self
+    }
+
+    /// Enable incremental static generation, backing the render cache with a
+    /// custom [`CacheStore`] instead of the default filesystem store.
+    ///
+    /// Use this when the local disk is ephemeral or not shared across nodes and
+    /// you want to persist prerendered pages in Redis, S3, an in-process LRU, or
+    /// similar. The config's `cache_dir` only affects the default store, so it
+    /// is ignored here.
+    pub fn incremental_with_store(
+        mut self,
+        cfg: IncrementalConfig,
+        store: impl CacheStore,
+    ) -> Self {
+        self.incremental = Some(cfg);
+        self.store = Some(Arc::new(store));
         self
     }
 
@@ -103,12 +497,30 @@ impl<P: Clone> ServeConfigBuilder<P> {
 
         let index = load_index_html(index_path, root_id);
 
+        // Default the render cache to a filesystem store rooted at a dedicated
+        // cache directory — kept distinct from `assets_path` so it can never
+        // read or overwrite the index template (`{assets_path}/index.html`).
+        // This mirrors the incremental renderer's own on-disk output directory.
+        let store = self.store.or_else(|| {
+            self.incremental.as_ref().map(|cfg| {
+                let dir = cfg
+                    .cache_dir
+                    .clone()
+                    .unwrap_or_else(|| PathBuf::from(DEFAULT_INCREMENTAL_CACHE_DIR));
+                Arc::new(FileCacheStore::new(dir)) as Arc<dyn CacheStore>
+            })
+        });
+
         ServeConfig {
             app: self.app,
             props: self.props,
             index,
             assets_path,
             incremental: self.incremental,
+            store,
+            strategies: self.strategies,
+            props_for_route: self.props_for_route,
+            revalidating: Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
         }
     }
 }
@@ -152,8 +564,164 @@ pub struct ServeConfig<P: Clone> {
     pub(crate) index: IndexHtml,
     /// The assets path.
     pub assets_path: &'static str,
-    pub(crate) incremental:
-        Option<std::sync::Arc<dioxus_ssr::incremental::IncrementalRendererConfig>>,
+    pub(crate) incremental: Option<IncrementalConfig>,
+    pub(crate) store: Option<Arc<dyn CacheStore>>,
+    pub(crate) strategies: Option<RouteStrategies>,
+    pub(crate) props_for_route: Option<PropsForRoute<P>>,
+    /// Routes with a background revalidation currently in flight, so only one
+    /// re-render is spawned per stale window instead of one per request.
+    pub(crate) revalidating: Arc<std::sync::Mutex<std::collections::HashSet<String>>>,
+}
+
+impl<P: Clone> ServeConfig<P> {
+    /// Force regeneration of a route by dropping its cached entry.
+    ///
+    /// Intended to be reached from application code — for example a server
+    /// function wired to a CMS webhook — so specific pages can be invalidated
+    /// on demand. A no-op when no render cache is configured.
+    pub async fn invalidate(&self, route: &str) {
+        if let Some(store) = &self.store {
+            store.invalidate(route).await;
+        }
+    }
+
+    /// Serve `route` from the render cache, applying incremental
+    /// stale-while-revalidate semantics.
+    ///
+    /// A fresh entry is returned directly. An entry older than `revalidate` is
+    /// still returned immediately, but a background re-render is spawned so the
+    /// next request sees fresh content — the responding request never blocks on
+    /// it. When nothing is cached the page is rendered inline and stored.
+    pub(crate) async fn serve_incremental<F, Fut>(
+        &self,
+        route: &str,
+        revalidate: Option<Duration>,
+        render: F,
+    ) -> Vec<u8>
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = Vec<u8>> + Send + 'static,
+    {
+        let store = match &self.store {
+            Some(store) => store.clone(),
+            // Without a store there is nothing to cache against, so always
+            // render inline.
+            None => return render().await,
+        };
+
+        if let Some((bytes, generated_at)) = store.get(route).await {
+            if is_stale(generated_at, revalidate) {
+                spawn_revalidate(store, route.to_string(), render, self.revalidating.clone());
+            }
+            return bytes;
+        }
+
+        let bytes = render().await;
+        store.put(route, bytes.clone()).await;
+        bytes
+    }
+
+    /// The [`RenderStrategy`] the server should use for `route`.
+    ///
+    /// Defaults to [`RenderStrategy::Incremental`] when incremental rendering is
+    /// enabled but no per-route strategies were declared, carrying the TTL set
+    /// through [`IncrementalConfig::invalidate_after`] so stale-while-revalidate
+    /// works through the plain `incremental`/`incremental_with_store` entry
+    /// points without a router. Falls back to [`RenderStrategy::Server`]
+    /// otherwise.
+    pub(crate) fn strategy_for(&self, route: &str) -> RenderStrategy {
+        match &self.strategies {
+            Some(strategies) => strategies(route),
+            None => match &self.incremental {
+                Some(cfg) => RenderStrategy::Incremental {
+                    revalidate: cfg.invalidate_after,
+                },
+                None => RenderStrategy::Server,
+            },
+        }
+    }
+}
+
+impl<P: Clone + Send + 'static> ServeConfig<P> {
+    /// Render `route` for an incoming request, going through the configured
+    /// [`CacheStore`].
+    ///
+    /// The server consults the [`RenderStrategy`] for the route:
+    /// [`RenderStrategy::Static`] serves from the store unconditionally and
+    /// returns [`RenderError::MissingStatic`] on a miss (static pages are
+    /// produced ahead of time, so a miss is a 404 rather than a cue to
+    /// server-render), [`RenderStrategy::Server`] always renders fresh, and
+    /// [`RenderStrategy::Incremental`] serves from the store. In every cached
+    /// case the configured [`CacheStore`] is the single source of truth for
+    /// prerendered pages instead of dioxus-ssr's built-in on-disk cache.
+    pub async fn render_route(&self, route: &str) -> Result<Vec<u8>, RenderError> {
+        match self.strategy_for(route) {
+            RenderStrategy::Server => Ok(self.render_page(route)),
+            // Static pages are prerendered at build time (e.g. by
+            // `generate_static_site`). A missing entry is a genuine 404: we do
+            // not silently server-render, which would defeat the
+            // prerendered-at-build contract and mask a missing export.
+            RenderStrategy::Static => match &self.store {
+                Some(store) => match store.get(route).await {
+                    Some((bytes, _)) => Ok(bytes),
+                    None => Err(RenderError::MissingStatic(route.to_string())),
+                },
+                None => Err(RenderError::MissingStatic(route.to_string())),
+            },
+            // Serve from the store and, once the entry is older than the TTL,
+            // regenerate it in the background without blocking this request.
+            RenderStrategy::Incremental { revalidate } => Ok(self
+                .serve_incremental(route, revalidate, self.page_renderer(route))
+                .await),
+        }
+    }
+
+    /// Render the full HTML page for `route` by wrapping its [`dioxus-ssr`]
+    /// output in the pre/post-main split of the index template.
+    ///
+    /// For router apps the route is threaded into the router config so each
+    /// path renders its own page; other apps render the same props regardless
+    /// of `route`.
+    pub(crate) fn render_page(&self, route: &str) -> Vec<u8> {
+        render_page(self.app, self.props_for(route), &self.index)
+    }
+
+    /// The props to render `route` with, rebuilt from the route for router apps
+    /// and cloned verbatim otherwise.
+    fn props_for(&self, route: &str) -> P {
+        match &self.props_for_route {
+            Some(build) => build(route),
+            None => self.props.clone(),
+        }
+    }
+
+    /// Build an owned renderer for `route` that can be handed to
+    /// [`serve_incremental`](Self::serve_incremental) and re-run from a
+    /// background task.
+    fn page_renderer(
+        &self,
+        route: &str,
+    ) -> impl Fn() -> std::future::Ready<Vec<u8>> + Send + Sync + 'static {
+        let app = self.app;
+        let props = self.props_for(route);
+        let index = self.index.clone();
+        move || std::future::ready(render_page(app, props.clone(), &index))
+    }
+}
+
+/// Render the full HTML page for `app`/`props`, injecting the [`dioxus-ssr`]
+/// body into the pre/post-main split of the index template.
+fn render_page<P: Clone + 'static>(app: Component<P>, props: P, index: &IndexHtml) -> Vec<u8> {
+    let mut vdom = VirtualDom::new_with_props(app, props);
+    let _ = vdom.rebuild();
+    let body = dioxus_ssr::render(&vdom);
+
+    let mut html =
+        String::with_capacity(index.pre_main.len() + body.len() + index.post_main.len());
+    html.push_str(&index.pre_main);
+    html.push_str(&body);
+    html.push_str(&index.post_main);
+    html.into_bytes()
 }
 
 impl<P: Clone> From<ServeConfigBuilder<P>> for ServeConfig<P> {
@@ -161,3 +729,170 @@ impl<P: Clone> From<ServeConfigBuilder<P>> for ServeConfig<P> {
         builder.build()
     }
 }
+
+/// Whether a cache entry generated at `generated_at` has outlived its TTL.
+///
+/// A `revalidate` of `None` means the entry never expires on its own. A clock
+/// that reports the entry as generated in the future is treated as stale so a
+/// corrupt timestamp can't pin a page forever.
+fn is_stale(generated_at: SystemTime, revalidate: Option<Duration>) -> bool {
+    match revalidate {
+        Some(ttl) => generated_at.elapsed().map(|age| age > ttl).unwrap_or(true),
+        None => false,
+    }
+}
+
+/// Spawn a background task that re-renders `route` and writes it back to the
+/// store, so the responding request can return the stale copy without waiting.
+///
+/// `inflight` guards against a thundering herd: while a stale entry is being
+/// regenerated, every concurrent request still sees it stale, so this only
+/// spawns a render when the route isn't already being revalidated, clearing the
+/// flag once the write completes.
+fn spawn_revalidate<F, Fut>(
+    store: Arc<dyn CacheStore>,
+    route: String,
+    render: F,
+    inflight: Arc<std::sync::Mutex<std::collections::HashSet<String>>>,
+) where
+    F: Fn() -> Fut + Send + Sync + 'static,
+    Fut: std::future::Future<Output = Vec<u8>> + Send + 'static,
+{
+    {
+        let mut inflight = inflight.lock().unwrap();
+        if !inflight.insert(route.clone()) {
+            // A revalidation for this route is already running.
+            return;
+        }
+    }
+
+    tokio::spawn(async move {
+        // Clear the in-flight flag from a drop guard so a panicking or
+        // unwinding render/put can't pin the route in the `revalidating` set
+        // forever, which would stop every future background re-render for it.
+        let _guard = InflightGuard {
+            inflight: &inflight,
+            route: &route,
+        };
+        let bytes = render().await;
+        store.put(&route, bytes).await;
+    });
+}
+
+/// Removes a route from the in-flight revalidation set when dropped, so the
+/// flag is cleared even if the background render unwinds.
+struct InflightGuard<'a> {
+    inflight: &'a Arc<std::sync::Mutex<std::collections::HashSet<String>>>,
+    route: &'a str,
+}
+
+impl Drop for InflightGuard<'_> {
+    fn drop(&mut self) {
+        // A poisoned lock still lets us take the inner set and clear the entry.
+        let mut inflight = self
+            .inflight
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        inflight.remove(self.route);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_cache_store_path_for_maps_routes_to_index_files() {
+        let store = FileCacheStore::new("cache");
+        assert_eq!(
+            store.path_for("/"),
+            Some(PathBuf::from("cache/index.html"))
+        );
+        assert_eq!(store.path_for(""), Some(PathBuf::from("cache/index.html")));
+        assert_eq!(
+            store.path_for("/blog/post"),
+            Some(PathBuf::from("cache/blog/post/index.html"))
+        );
+        // Leading and trailing slashes are trimmed before mapping.
+        assert_eq!(
+            store.path_for("blog/post/"),
+            Some(PathBuf::from("cache/blog/post/index.html"))
+        );
+    }
+
+    #[test]
+    fn file_cache_store_path_for_rejects_traversal() {
+        let store = FileCacheStore::new("cache");
+        // A `..` component must never escape the cache root.
+        assert_eq!(store.path_for("/../../etc/passwd"), None);
+        assert_eq!(store.path_for("blog/../../secret"), None);
+        assert_eq!(store.path_for("."), None);
+        // Doubled slashes leave an empty segment, which is rejected too.
+        assert_eq!(store.path_for("blog//post"), None);
+    }
+
+    #[test]
+    fn is_stale_honors_ttl_and_guards_future_timestamps() {
+        let now = SystemTime::now();
+
+        // No TTL means an entry never expires on its own.
+        assert!(!is_stale(now, None));
+
+        // An entry older than the TTL is stale; a fresh one is not.
+        let old = now - Duration::from_secs(120);
+        assert!(is_stale(old, Some(Duration::from_secs(60))));
+        assert!(!is_stale(now, Some(Duration::from_secs(60))));
+
+        // A timestamp in the future (clock skew / corruption) is treated as
+        // stale so it can't pin a page forever.
+        let future = now + Duration::from_secs(120);
+        assert!(is_stale(future, Some(Duration::from_secs(60))));
+    }
+
+    #[cfg(feature = "router")]
+    #[test]
+    fn static_site_path_mirrors_routes() {
+        let out = std::path::Path::new("out");
+        assert_eq!(static_site_path(out, "/"), PathBuf::from("out/index.html"));
+        assert_eq!(
+            static_site_path(out, "/blog/post"),
+            PathBuf::from("out/blog/post/index.html")
+        );
+    }
+
+    #[cfg(feature = "router")]
+    #[test]
+    fn flatten_site_map_emits_static_endpoints_and_skips_dynamic() {
+        use dioxus_router::prelude::{SegmentType, SiteMapSegment};
+
+        // A `/blog` index with a static `/blog/post` child and a dynamic
+        // `/blog/:slug/comments` subtree.
+        static COMMENTS: &[SiteMapSegment] = &[SiteMapSegment {
+            segment_type: SegmentType::Static("comments"),
+            children: &[],
+        }];
+        static BLOG_CHILDREN: &[SiteMapSegment] = &[
+            SiteMapSegment {
+                segment_type: SegmentType::Static("post"),
+                children: &[],
+            },
+            SiteMapSegment {
+                segment_type: SegmentType::Dynamic("slug"),
+                children: COMMENTS,
+            },
+        ];
+        static SITE_MAP: &[SiteMapSegment] = &[SiteMapSegment {
+            segment_type: SegmentType::Static("blog"),
+            children: BLOG_CHILDREN,
+        }];
+
+        let mut routes = Vec::new();
+        flatten_site_map("", SITE_MAP, &mut routes);
+
+        // The intermediate `/blog` index and its static child are both emitted;
+        // the dynamic `:slug` subtree is skipped rather than mangled into
+        // `/blog/comments`.
+        assert_eq!(routes, vec!["/blog".to_string(), "/blog/post".to_string()]);
+        assert!(!routes.iter().any(|r| r.contains("comments")));
+    }
+}